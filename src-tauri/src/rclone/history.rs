@@ -0,0 +1,217 @@
+//! Local SQLite history of completed transfers.
+//!
+//! `get_completed_transfers` only surfaces what rclone still holds in memory,
+//! which is lost on engine restart and capped by rclone's retention. This
+//! subsystem ingests the `transferred` array on each poll into an embedded
+//! SQLite database so the UI can render historical throughput charts and a
+//! per-remote audit log that survives restarts.
+
+use std::path::Path;
+use std::sync::Mutex;
+
+use log::{debug, info};
+use rusqlite::{Connection, params};
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::rclone::queries::protocol::TransferredItem;
+
+/// Database handle guarded behind a mutex, managed as Tauri state.
+pub struct DbCtx {
+    conn: Mutex<Connection>,
+}
+
+impl DbCtx {
+    /// Open (creating if needed) the history database at `path` and run schema
+    /// migrations.
+    pub fn open(path: &Path) -> Result<Self, String> {
+        let conn = Connection::open(path).map_err(|e| format!("Failed to open history db: {e}"))?;
+        let ctx = DbCtx { conn: Mutex::new(conn) };
+        ctx.migrate()?;
+        Ok(ctx)
+    }
+
+    /// Apply schema migrations. Idempotent, run on every startup.
+    fn migrate(&self) -> Result<(), String> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS transfers (
+                id            INTEGER PRIMARY KEY AUTOINCREMENT,
+                name          TEXT NOT NULL,
+                src_fs        TEXT NOT NULL DEFAULT '',
+                dst_fs        TEXT NOT NULL DEFAULT '',
+                grp           TEXT NOT NULL DEFAULT '',
+                bytes         INTEGER NOT NULL DEFAULT 0,
+                error         TEXT NOT NULL DEFAULT '',
+                checked       INTEGER NOT NULL DEFAULT 0,
+                completed_at  TEXT NOT NULL DEFAULT '',
+                -- rclone's core/transferred entries carry no completion
+                -- timestamp, so we cannot dedup on a real completedAt as
+                -- originally sketched. Dedup on (src_fs, dst_fs, name, bytes)
+                -- instead: this INTENTIONALLY coalesces repeated transfers of a
+                -- file whose size is unchanged (e.g. a scheduled sync re-copying
+                -- the same bytes) into a single audit row, which keeps the table
+                -- bounded across restarts at the cost of not distinguishing
+                -- byte-identical re-runs.
+                UNIQUE(src_fs, dst_fs, name, bytes)
+            );
+            CREATE INDEX IF NOT EXISTS idx_transfers_completed_at ON transfers(completed_at);
+            CREATE INDEX IF NOT EXISTS idx_transfers_grp ON transfers(grp);",
+        )
+        .map_err(|e| format!("Failed to migrate history db: {e}"))?;
+        info!("🗄️ Transfer history schema ready");
+        Ok(())
+    }
+
+    /// Ingest a batch of completed transfers, skipping rows already stored.
+    ///
+    /// Deduplication is enforced by the `(src_fs, dst_fs, name, bytes)` unique
+    /// index via `INSERT OR IGNORE`. rclone's `core/transferred` entries carry no
+    /// completion timestamp, so `completed_at` is stamped at first-ingest time;
+    /// `INSERT OR IGNORE` keeps that original stamp on later re-observations of
+    /// the same transfer. Note that byte-identical re-transfers of the same file
+    /// are intentionally coalesced (see the schema comment), so a scheduled sync
+    /// re-copying an unchanged file does not add a new row. Windows path
+    /// normalization has already been applied while deserializing
+    /// [`TransferredItem`].
+    pub fn ingest(&self, items: &[TransferredItem]) -> Result<usize, String> {
+        let now = chrono::Utc::now().to_rfc3339();
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn
+            .transaction()
+            .map_err(|e| format!("Failed to begin transaction: {e}"))?;
+        let mut inserted = 0;
+        {
+            let mut stmt = tx
+                .prepare(
+                    "INSERT OR IGNORE INTO transfers
+                        (name, src_fs, dst_fs, grp, bytes, error, checked, completed_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                )
+                .map_err(|e| format!("Failed to prepare insert: {e}"))?;
+            for item in items {
+                let changed = stmt
+                    .execute(params![
+                        item.name,
+                        item.src_fs.clone().unwrap_or_default(),
+                        item.dst_fs.clone().unwrap_or_default(),
+                        item.group.clone().unwrap_or_default(),
+                        item.bytes,
+                        item.error,
+                        item.checked as i64,
+                        now,
+                    ])
+                    .map_err(|e| format!("Failed to insert transfer: {e}"))?;
+                inserted += changed;
+            }
+        }
+        tx.commit()
+            .map_err(|e| format!("Failed to commit transfers: {e}"))?;
+        debug!("🗄️ Ingested {inserted} new transfer(s)");
+        Ok(inserted)
+    }
+}
+
+/// A stored transfer row.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferRecord {
+    pub name: String,
+    pub src_fs: String,
+    pub dst_fs: String,
+    pub group: String,
+    pub bytes: i64,
+    pub error: String,
+    pub checked: bool,
+    pub completed_at: String,
+}
+
+/// Per-day throughput total for a group.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyTotal {
+    pub day: String,
+    pub bytes: i64,
+    pub transfers: i64,
+}
+
+/// Query the transfer history, optionally filtered by a substring of the name
+/// and a `completed_at` window, newest first.
+#[tauri::command]
+pub async fn get_transfer_history(
+    db: State<'_, DbCtx>,
+    filter: Option<String>,
+    since: Option<String>,
+    until: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<TransferRecord>, String> {
+    let conn = db.conn.lock().unwrap();
+    let like = filter.map(|f| format!("%{f}%")).unwrap_or_else(|| "%".to_string());
+    let since = since.unwrap_or_default();
+    let until = until.unwrap_or_else(|| "9999".to_string());
+    let limit = limit.unwrap_or(500);
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT name, src_fs, dst_fs, grp, bytes, error, checked, completed_at
+             FROM transfers
+             WHERE name LIKE ?1 AND completed_at >= ?2 AND completed_at <= ?3
+             ORDER BY completed_at DESC
+             LIMIT ?4",
+        )
+        .map_err(|e| format!("Failed to prepare query: {e}"))?;
+
+    let rows = stmt
+        .query_map(params![like, since, until, limit], |row| {
+            Ok(TransferRecord {
+                name: row.get(0)?,
+                src_fs: row.get(1)?,
+                dst_fs: row.get(2)?,
+                group: row.get(3)?,
+                bytes: row.get(4)?,
+                error: row.get(5)?,
+                checked: row.get::<_, i64>(6)? != 0,
+                completed_at: row.get(7)?,
+            })
+        })
+        .map_err(|e| format!("Failed to query history: {e}"))?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read history rows: {e}"))
+}
+
+/// Aggregate bytes and transfer counts per calendar day for `group`
+/// (all groups when `None`), oldest first.
+#[tauri::command]
+pub async fn get_transfer_totals_by_day(
+    db: State<'_, DbCtx>,
+    group: Option<String>,
+) -> Result<Vec<DailyTotal>, String> {
+    let conn = db.conn.lock().unwrap();
+    let grp = group.unwrap_or_else(|| "%".to_string());
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT substr(completed_at, 1, 10) AS day,
+                    SUM(bytes) AS bytes,
+                    COUNT(*) AS transfers
+             FROM transfers
+             WHERE grp LIKE ?1
+             GROUP BY day
+             ORDER BY day ASC",
+        )
+        .map_err(|e| format!("Failed to prepare totals query: {e}"))?;
+
+    let rows = stmt
+        .query_map(params![grp], |row| {
+            Ok(DailyTotal {
+                day: row.get(0)?,
+                bytes: row.get(1)?,
+                transfers: row.get(2)?,
+            })
+        })
+        .map_err(|e| format!("Failed to query totals: {e}"))?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read totals rows: {e}"))
+}
@@ -0,0 +1,160 @@
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use log::{debug, error, info};
+use metrics::{counter, gauge};
+use metrics_exporter_prometheus::PrometheusBuilder;
+use serde_json::Value;
+use tauri::State;
+
+use crate::RcloneState;
+use crate::rclone::state::ENGINE_STATE;
+use crate::utils::rclone::endpoints::{EndpointHelper, core};
+
+/// Default address the Prometheus exporter binds to when `RcloneState` does not
+/// override it.
+const DEFAULT_BIND: &str = "127.0.0.1:9191";
+/// Default interval between `core/stats` scrapes.
+const DEFAULT_INTERVAL_MS: u64 = 5_000;
+
+/// Set once the process-global recorder has been installed and the scrape task
+/// spawned, so repeated command invocations are idempotent rather than erroring
+/// on the second `install()` and leaking a duplicate scrape task.
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+/// Install the global Prometheus recorder and start the background scrape loop.
+///
+/// The recorder serves `/metrics` on [`RcloneState::metrics_bind`] (falling back
+/// to [`DEFAULT_BIND`]) and every [`RcloneState::metrics_interval_ms`] the loop
+/// polls the same `core/stats` endpoint as `get_core_stats`, mapping the JSON
+/// payload onto registered gauges and counters. Only the global `core/stats`
+/// response is scraped; its `transferring` entries keep a `group` label, but the
+/// per-group totals returned by `get_core_stats_filtered` are not recorded.
+#[tauri::command]
+pub async fn start_metrics_exporter(state: State<'_, RcloneState>) -> Result<(), String> {
+    // `PrometheusBuilder::install()` registers a process-global recorder, so a
+    // second install would error and a second scrape task would leak. Install
+    // at most once; later calls are a no-op.
+    if INSTALLED.swap(true, Ordering::SeqCst) {
+        debug!("📈 Metrics exporter already running; ignoring duplicate start");
+        return Ok(());
+    }
+
+    // On any setup failure, release the guard so the command can be retried.
+    let setup = (|| {
+        let bind: SocketAddr = state
+            .metrics_bind
+            .clone()
+            .unwrap_or_else(|| DEFAULT_BIND.to_string())
+            .parse()
+            .map_err(|e| format!("Invalid metrics bind address: {e}"))?;
+
+        PrometheusBuilder::new()
+            .with_http_listener(bind)
+            .install()
+            .map_err(|e| {
+                error!("❌ Failed to install Prometheus recorder: {e}");
+                format!("Failed to install Prometheus recorder: {e}")
+            })?;
+        Ok::<SocketAddr, String>(bind)
+    })();
+
+    let bind = match setup {
+        Ok(bind) => bind,
+        Err(e) => {
+            INSTALLED.store(false, Ordering::SeqCst);
+            return Err(e);
+        }
+    };
+
+    let interval = Duration::from_millis(state.metrics_interval_ms.unwrap_or(DEFAULT_INTERVAL_MS));
+
+    info!("📈 Prometheus metrics exporter listening on http://{bind}/metrics");
+
+    let client = state.client.clone();
+    tauri::async_runtime::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = scrape_once(&client).await {
+                error!("❌ Metrics scrape failed: {e}");
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Poll `core/stats` once and translate the response into metric updates.
+async fn scrape_once(client: &reqwest::Client) -> Result<(), String> {
+    let url = EndpointHelper::build_url(&ENGINE_STATE.get_api().0, core::STATS);
+
+    let response = client
+        .post(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to get core stats: {e}"))?;
+
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    if !status.is_success() {
+        return Err(format!("HTTP {status}: {body}"));
+    }
+
+    let stats: Value =
+        serde_json::from_str(&body).map_err(|e| format!("Failed to parse core stats: {e}"))?;
+
+    record_stats(&stats);
+    Ok(())
+}
+
+/// Map the `core/stats` JSON onto the registered metrics.
+///
+/// Note: per-group `transferring` gauges are only ever set, never cleared. Once
+/// a group finishes it drops out of the `transferring` array, so its gauge stays
+/// pinned at the last observed value rather than resetting to zero. Consumers
+/// should treat a stale `rclone_transferring_*{group=...}` series accordingly
+/// (e.g. alert on the absence of fresh samples).
+fn record_stats(stats: &Value) {
+    // Cumulative fields are exposed as counters, instantaneous ones as gauges.
+    if let Some(bytes) = stats.get("bytes").and_then(Value::as_u64) {
+        counter!("rclone_bytes_total").absolute(bytes);
+    }
+    if let Some(transfers) = stats.get("transfers").and_then(Value::as_u64) {
+        counter!("rclone_transfers_total").absolute(transfers);
+    }
+    if let Some(errors) = stats.get("errors").and_then(Value::as_u64) {
+        counter!("rclone_errors_total").absolute(errors);
+    }
+    if let Some(checks) = stats.get("checks").and_then(Value::as_u64) {
+        counter!("rclone_checks_total").absolute(checks);
+    }
+    if let Some(speed) = stats.get("speed").and_then(Value::as_f64) {
+        gauge!("rclone_speed_bytes_per_second").set(speed);
+    }
+    if let Some(elapsed) = stats.get("elapsedTime").and_then(Value::as_f64) {
+        gauge!("rclone_elapsed_seconds").set(elapsed);
+    }
+
+    // In-flight transfers carry a `group` label taken from each entry's own
+    // `group` field ("global" when absent). When a group has several entries in
+    // flight, its gauges hold the values of the last entry in the array.
+    if let Some(transferring) = stats.get("transferring").and_then(Value::as_array) {
+        for item in transferring {
+            let group = item
+                .get("group")
+                .and_then(Value::as_str)
+                .unwrap_or("global")
+                .to_string();
+            if let Some(bytes) = item.get("bytes").and_then(Value::as_f64) {
+                gauge!("rclone_transferring_bytes", "group" => group.clone()).set(bytes);
+            }
+            if let Some(speed) = item.get("speed").and_then(Value::as_f64) {
+                gauge!("rclone_transferring_speed_bytes_per_second", "group" => group).set(speed);
+            }
+        }
+    }
+
+    debug!("📈 Recorded core stats into Prometheus registry");
+}
@@ -0,0 +1,6 @@
+pub mod history;
+pub mod metrics;
+pub mod notifier;
+pub mod queries;
+pub mod state;
+pub mod stats_stream;
@@ -0,0 +1,276 @@
+//! Pluggable notifications driven by the stats stream.
+//!
+//! The notifier watches [`StatsDelta`] events and fires on configurable
+//! conditions — a tracked job finishing, transfer errors crossing a threshold,
+//! or a group's in-flight transfer count reaching zero. Delivery channels are
+//! trait objects so new sinks can be added without touching the evaluation
+//! logic, and conditions key off `jobid`/`group` the same way
+//! `get_core_stats_filtered` does.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use log::{debug, error};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tauri::AppHandle;
+use tauri_plugin_notification::NotificationExt;
+use tokio::sync::Mutex;
+
+use crate::rclone::stats_stream::StatsDelta;
+
+/// Process-global notifier fed by the stats-stream poller.
+static NOTIFIER: Lazy<Mutex<Notifier>> = Lazy::new(|| Mutex::new(Notifier::new()));
+
+/// Register a rule on the global notifier from the frontend.
+#[tauri::command]
+pub async fn add_notifier_rule(
+    app: AppHandle,
+    condition: NotifyCondition,
+    channels: Vec<NotifierConfig>,
+) -> Result<(), String> {
+    NOTIFIER.lock().await.add_rule(&app, condition, channels);
+    Ok(())
+}
+
+/// Hand a stats delta to the global notifier; called from the poll loop.
+///
+/// Evaluation (which updates per-rule edge state) happens under the lock, but
+/// the lock is released before awaiting deliveries so a slow webhook or email
+/// sink can't block other groups' dispatches or `add_notifier_rule`.
+pub async fn dispatch(delta: &StatsDelta) {
+    let deliveries = NOTIFIER.lock().await.evaluate(delta);
+    for (sink, event) in deliveries {
+        if let Err(e) = sink.deliver(&event).await {
+            error!("❌ Notification delivery failed: {e}");
+        }
+    }
+}
+
+/// Condition under which a rule fires, scoped to a group (or a `job/<id>`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotifyCondition {
+    /// The group/job has no remaining in-flight transfers (job done).
+    JobFinished { group: String },
+    /// Cumulative errors for the group exceeded `threshold`.
+    ErrorsExceed { group: String, threshold: i64 },
+    /// Newly completed transfers appeared this sample.
+    TransfersCompleted { group: String },
+}
+
+impl NotifyCondition {
+    /// The group this condition is scoped to.
+    fn group(&self) -> &str {
+        match self {
+            NotifyCondition::JobFinished { group }
+            | NotifyCondition::ErrorsExceed { group, .. }
+            | NotifyCondition::TransfersCompleted { group } => group,
+        }
+    }
+
+    /// Whether the condition currently holds for `delta` (level, not edge).
+    fn matches(&self, delta: &StatsDelta) -> bool {
+        if self.group() != delta.group {
+            return false;
+        }
+        match self {
+            NotifyCondition::JobFinished { .. } => delta.stats.transferring.is_empty(),
+            NotifyCondition::ErrorsExceed { threshold, .. } => delta.stats.errors > *threshold,
+            NotifyCondition::TransfersCompleted { .. } => delta.new_transfers > 0,
+        }
+    }
+
+    /// Whether this condition should fire only on the false→true transition.
+    ///
+    /// `JobFinished` and `ErrorsExceed` are level conditions that stay true on
+    /// every subsequent poll, so they must be edge-triggered to avoid a
+    /// notification flood. `TransfersCompleted` already keys off the per-sample
+    /// `new_transfers` delta, so it is naturally one-shot.
+    fn edge_triggered(&self) -> bool {
+        matches!(
+            self,
+            NotifyCondition::JobFinished { .. } | NotifyCondition::ErrorsExceed { .. }
+        )
+    }
+}
+
+/// Declarative description of a delivery channel.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "kind")]
+pub enum NotifierConfig {
+    /// A desktop notification via Tauri's notification plugin.
+    Desktop,
+    /// An outbound webhook POST carrying a JSON payload.
+    Webhook { url: String },
+    /// An email delivery. The email sink is not implemented: every delivery
+    /// through it returns an error.
+    Email { to: String },
+}
+
+impl NotifierConfig {
+    /// Build the runtime sink for this config. The [`AppHandle`] is threaded in
+    /// so the desktop sink can reach Tauri's notification plugin.
+    pub fn into_sink(self, app: &AppHandle) -> Arc<dyn NotificationSink> {
+        match self {
+            NotifierConfig::Desktop => Arc::new(DesktopSink { app: app.clone() }),
+            NotifierConfig::Webhook { url } => Arc::new(WebhookSink { url }),
+            NotifierConfig::Email { to } => Arc::new(EmailSink { to }),
+        }
+    }
+}
+
+/// The payload handed to a sink when a condition fires.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationEvent {
+    pub group: String,
+    pub bytes: i64,
+    pub errors: i64,
+    pub elapsed: f64,
+    pub message: String,
+}
+
+impl NotificationEvent {
+    fn from_delta(delta: &StatsDelta, message: String) -> Self {
+        NotificationEvent {
+            group: delta.group.clone(),
+            bytes: delta.stats.bytes,
+            errors: delta.stats.errors,
+            elapsed: delta.stats.elapsed_time,
+            message,
+        }
+    }
+}
+
+/// A delivery channel. Implement this to add a new sink.
+#[async_trait]
+pub trait NotificationSink: Send + Sync {
+    async fn deliver(&self, event: &NotificationEvent) -> Result<(), String>;
+}
+
+/// Desktop notification via Tauri's notification plugin.
+struct DesktopSink {
+    app: AppHandle,
+}
+
+#[async_trait]
+impl NotificationSink for DesktopSink {
+    async fn deliver(&self, event: &NotificationEvent) -> Result<(), String> {
+        self.app
+            .notification()
+            .builder()
+            .title("RClone Manager")
+            .body(&event.message)
+            .show()
+            .map_err(|e| format!("Failed to show desktop notification: {e}"))?;
+        debug!("🔔 Desktop notification shown for group '{}'", event.group);
+        Ok(())
+    }
+}
+
+/// Outbound webhook that POSTs the event as JSON.
+struct WebhookSink {
+    url: String,
+}
+
+#[async_trait]
+impl NotificationSink for WebhookSink {
+    async fn deliver(&self, event: &NotificationEvent) -> Result<(), String> {
+        let payload = json!({
+            "group": event.group,
+            "bytes": event.bytes,
+            "errors": event.errors,
+            "elapsed": event.elapsed,
+            "message": event.message,
+        });
+        reqwest::Client::new()
+            .post(&self.url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| format!("Failed to POST webhook: {e}"))?;
+        debug!("🔔 Delivered webhook to {}", self.url);
+        Ok(())
+    }
+}
+
+/// Email sink. Unimplemented: wiring an SMTP transport is left for a follow-up,
+/// so this reports failure rather than pretending a mail was delivered.
+struct EmailSink {
+    to: String,
+}
+
+#[async_trait]
+impl NotificationSink for EmailSink {
+    async fn deliver(&self, _event: &NotificationEvent) -> Result<(), String> {
+        Err(format!(
+            "email notifications are not implemented yet (would notify {})",
+            self.to
+        ))
+    }
+}
+
+/// A condition bound to the sinks that fire when it matches.
+pub struct Rule {
+    pub condition: NotifyCondition,
+    pub sinks: Vec<Arc<dyn NotificationSink>>,
+    /// Whether the condition held on the previous sample, for edge detection.
+    last_level: bool,
+}
+
+/// Evaluates [`StatsDelta`] events against a set of rules and dispatches to the
+/// matching sinks. Evaluation is decoupled from delivery.
+#[derive(Default)]
+pub struct Notifier {
+    rules: Vec<Rule>,
+}
+
+impl Notifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a rule built from declarative configs.
+    pub fn add_rule(
+        &mut self,
+        app: &AppHandle,
+        condition: NotifyCondition,
+        channels: Vec<NotifierConfig>,
+    ) {
+        let sinks = channels.into_iter().map(|c| c.into_sink(app)).collect();
+        self.rules.push(Rule {
+            condition,
+            sinks,
+            last_level: false,
+        });
+    }
+
+    /// Evaluate a stats delta, updating per-rule edge state, and return the
+    /// (sink, event) pairs that should be delivered. Cheap `Arc` clones let the
+    /// caller await delivery after dropping the lock.
+    fn evaluate(&mut self, delta: &StatsDelta) -> Vec<(Arc<dyn NotificationSink>, NotificationEvent)> {
+        let mut deliveries = Vec::new();
+        for rule in &mut self.rules {
+            let level = rule.condition.matches(delta);
+            let fire = if rule.condition.edge_triggered() {
+                level && !rule.last_level
+            } else {
+                level
+            };
+            rule.last_level = level;
+            if !fire {
+                continue;
+            }
+            let event = NotificationEvent::from_delta(
+                delta,
+                format!("Condition met for group '{}'", delta.group),
+            );
+            for sink in &rule.sinks {
+                deliveries.push((Arc::clone(sink), event.clone()));
+            }
+        }
+        deliveries
+    }
+}
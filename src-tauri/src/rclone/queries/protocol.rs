@@ -0,0 +1,134 @@
+//! Strongly-typed models for the rclone `core/stats` and `core/transferred`
+//! responses.
+//!
+//! These mirror the JSON the rclone remote-control API returns so that callers
+//! (and the frontend, via the Tauri boundary) can work against a fixed protocol
+//! instead of re-parsing untyped [`serde_json::Value`] maps. The `dstFs`/`srcFs`
+//! fields run the Windows extended-length-path normalization as a
+//! [`Deserialize`] step so it applies uniformly to every response rather than
+//! being hand-applied at a single call site.
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Normalize Windows extended-length paths by stripping the `//?/` or `\\?\`
+/// prefix (`//?/C:/path` becomes `C:/path`, `\\?\C:\path` becomes `C:\path`);
+/// separators are left as they are. A no-op on non-Windows targets.
+fn normalize_fs_path(path: &str) -> String {
+    #[cfg(target_os = "windows")]
+    {
+        if path.starts_with("//?/") || path.starts_with(r"\\?\") {
+            return path[4..].to_string();
+        }
+    }
+    path.to_string()
+}
+
+/// `serde` adaptor that normalizes a filesystem path field while deserializing.
+fn deserialize_fs<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let raw = String::deserialize(deserializer)?;
+    Ok(normalize_fs_path(&raw))
+}
+
+/// Same as [`deserialize_fs`] but for optional path fields.
+fn deserialize_fs_opt<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let raw = Option::<String>::deserialize(deserializer)?;
+    Ok(raw.map(|p| normalize_fs_path(&p)))
+}
+
+/// A single in-flight transfer as reported under `core/stats` `transferring`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferringItem {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub size: i64,
+    #[serde(default)]
+    pub bytes: i64,
+    #[serde(default)]
+    pub speed: f64,
+    #[serde(default)]
+    pub percentage: f64,
+    #[serde(default)]
+    pub group: Option<String>,
+}
+
+/// A completed transfer as reported under `core/transferred` `transferred`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferredItem {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub size: i64,
+    #[serde(default)]
+    pub bytes: i64,
+    #[serde(default)]
+    pub checked: bool,
+    #[serde(default)]
+    pub error: String,
+    #[serde(default)]
+    pub group: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_fs_opt")]
+    pub src_fs: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_fs_opt")]
+    pub dst_fs: Option<String>,
+}
+
+/// The `core/stats` response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoreStats {
+    #[serde(default)]
+    pub bytes: i64,
+    #[serde(default)]
+    pub speed: f64,
+    #[serde(default)]
+    pub transfers: i64,
+    #[serde(default)]
+    pub errors: i64,
+    #[serde(default)]
+    pub checks: i64,
+    #[serde(default)]
+    pub elapsed_time: f64,
+    #[serde(default)]
+    pub transferring: Vec<TransferringItem>,
+}
+
+/// The `core/transferred` response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompletedTransfers {
+    #[serde(default)]
+    pub transferred: Vec<TransferredItem>,
+}
+
+/// Per-job statistics as returned by `core/stats` with a `jobid` payload.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobStats {
+    #[serde(default)]
+    pub bytes: i64,
+    #[serde(default)]
+    pub speed: f64,
+    #[serde(default)]
+    pub transfers: i64,
+    #[serde(default)]
+    pub errors: i64,
+    #[serde(default)]
+    pub checks: i64,
+    #[serde(default)]
+    pub elapsed_time: f64,
+    #[serde(default, deserialize_with = "deserialize_fs")]
+    pub src_fs: String,
+    #[serde(default, deserialize_with = "deserialize_fs")]
+    pub dst_fs: String,
+    #[serde(default)]
+    pub transferring: Vec<TransferringItem>,
+}
@@ -0,0 +1,195 @@
+//! Long-lived `core/stats` poller that pushes deltas to the frontend.
+//!
+//! Instead of the UI invoking `get_core_stats`/`get_job_stats` on a timer — each
+//! a fresh HTTP round-trip — a background task polls `core/stats` once per group
+//! and emits structured deltas over Tauri's event system as `core-stats-update`.
+//! Subscribers are reference-counted per group so polling stops once the last
+//! listener drops, mirroring the streaming runner-client design.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use log::{debug, info};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use serde_json::json;
+use tauri::{AppHandle, Emitter, Manager, State};
+use tauri::async_runtime::JoinHandle;
+
+use crate::RcloneState;
+use crate::rclone::history::DbCtx;
+use crate::rclone::notifier;
+use crate::rclone::queries::protocol::{CompletedTransfers, CoreStats};
+use crate::utils::rclone::api::post_rc_with;
+use crate::utils::rclone::endpoints::core;
+
+/// Event name the frontend subscribes to for streamed deltas.
+const STREAM_EVENT: &str = "core-stats-update";
+
+/// A running poller for one group together with its subscriber count.
+struct Stream {
+    subscribers: usize,
+    task: JoinHandle<()>,
+}
+
+/// Registry of active streams keyed by group (empty string = global stats).
+///
+/// Streams are started and stopped only by [`start_stats_stream`] and
+/// [`stop_stats_stream`]; they are not tied to the engine lifecycle.
+static STREAMS: Lazy<Mutex<HashMap<String, Stream>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Delta between two consecutive `core/stats` samples for a group.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsDelta {
+    pub group: String,
+    /// Throughput since the previous sample, in bytes per second.
+    pub bytes_per_second: f64,
+    /// Transfers completed since the previous sample.
+    pub new_transfers: i64,
+    /// Errors that appeared since the previous sample.
+    pub new_errors: i64,
+    /// The latest absolute stats snapshot.
+    pub stats: CoreStats,
+}
+
+/// Subscribe to streamed stats for `group`, starting the poller if needed.
+///
+/// Repeated calls for the same group bump the subscriber count rather than
+/// spawning a second poller.
+#[tauri::command]
+pub async fn start_stats_stream(
+    app: AppHandle,
+    state: State<'_, RcloneState>,
+    group: Option<String>,
+    interval_ms: Option<u64>,
+) -> Result<(), String> {
+    let group = group.unwrap_or_default();
+    let interval = Duration::from_millis(interval_ms.unwrap_or(2_000));
+
+    let mut streams = STREAMS.lock().unwrap();
+    if let Some(stream) = streams.get_mut(&group) {
+        stream.subscribers += 1;
+        debug!(
+            "📡 Added subscriber to stats stream '{group}' (now {})",
+            stream.subscribers
+        );
+        return Ok(());
+    }
+
+    let task = spawn_poller(app, state.client.clone(), group.clone(), interval);
+    streams.insert(group.clone(), Stream { subscribers: 1, task });
+    info!("📡 Started stats stream for group '{group}'");
+    Ok(())
+}
+
+/// Drop one subscription to `group`; stops the poller when the count hits zero.
+#[tauri::command]
+pub async fn stop_stats_stream(group: Option<String>) -> Result<(), String> {
+    let group = group.unwrap_or_default();
+    let mut streams = STREAMS.lock().unwrap();
+    if let Some(stream) = streams.get_mut(&group) {
+        stream.subscribers = stream.subscribers.saturating_sub(1);
+        if stream.subscribers == 0 {
+            if let Some(stream) = streams.remove(&group) {
+                stream.task.abort();
+                info!("📡 Stopped stats stream for group '{group}'");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Spawn the per-group polling loop, emitting a [`StatsDelta`] on every sample.
+fn spawn_poller(
+    app: AppHandle,
+    client: reqwest::Client,
+    group: String,
+    interval: Duration,
+) -> JoinHandle<()> {
+    tauri::async_runtime::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        let mut prev: Option<(CoreStats, Instant)> = None;
+        loop {
+            ticker.tick().await;
+
+            let payload = if group.is_empty() {
+                json!({})
+            } else {
+                json!({ "group": group })
+            };
+
+            let stats: CoreStats = match post_rc_with(&client, core::STATS, payload).await {
+                Ok(stats) => stats,
+                Err(e) => {
+                    debug!("⚠️ Stats stream '{group}' poll failed: {e}");
+                    continue;
+                }
+            };
+
+            // Measure against the previous successful sample, which may lie more
+            // than one interval back if polls in between failed.
+            let now = Instant::now();
+            let elapsed = prev
+                .as_ref()
+                .map_or(interval, |(_, at)| now.duration_since(*at));
+            let delta = compute_delta(&group, prev.as_ref().map(|(s, _)| s), &stats, elapsed);
+            if let Err(e) = app.emit(STREAM_EVENT, &delta) {
+                debug!("⚠️ Failed to emit {STREAM_EVENT} for '{group}': {e}");
+            }
+
+            // Ingest the completed-transfers snapshot into the history database
+            // on every poll, so the table is populated from the same loop.
+            let payload = if group.is_empty() {
+                json!({})
+            } else {
+                json!({ "group": group })
+            };
+            match post_rc_with::<CompletedTransfers, _>(&client, core::TRANSFERRED, payload).await {
+                Ok(transfers) => {
+                    if let Some(db) = app.try_state::<DbCtx>() {
+                        if let Err(e) = db.ingest(&transfers.transferred) {
+                            debug!("⚠️ Failed to ingest transfer history for '{group}': {e}");
+                        }
+                    }
+                }
+                Err(e) => debug!("⚠️ Stats stream '{group}' transferred poll failed: {e}"),
+            }
+
+            // Hand the delta to the notifier so configured rules can fire.
+            notifier::dispatch(&delta).await;
+
+            prev = Some((stats, now));
+        }
+    })
+}
+
+/// Derive per-second throughput and newly-appeared transfers/errors.
+fn compute_delta(
+    group: &str,
+    prev: Option<&CoreStats>,
+    current: &CoreStats,
+    elapsed: Duration,
+) -> StatsDelta {
+    let secs = elapsed.as_secs_f64().max(f64::EPSILON);
+    let (bytes_per_second, new_transfers, new_errors) = match prev {
+        Some(prev) => (
+            ((current.bytes - prev.bytes) as f64 / secs).max(0.0),
+            (current.transfers - prev.transfers).max(0),
+            (current.errors - prev.errors).max(0),
+        ),
+        // First sample: we have no baseline, so report the reported instantaneous
+        // speed but no "new" transfers/errors — the cumulative totals are not
+        // events that happened this interval.
+        None => (current.speed, 0, 0),
+    };
+
+    StatsDelta {
+        group: group.to_string(),
+        bytes_per_second,
+        new_transfers,
+        new_errors,
+        stats: current.clone(),
+    }
+}
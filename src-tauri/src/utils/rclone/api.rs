@@ -0,0 +1,79 @@
+//! Shared helper for issuing rclone remote-control POST requests.
+//!
+//! Every stats command used to duplicate the same build-url / send / check-status
+//! / parse-body sequence. [`post_rc`] centralizes it so commands only declare the
+//! endpoint, the payload, and the response type they expect, and routes every
+//! failure through the structured [`RcloneApiError`].
+
+use log::debug;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::RcloneState;
+use crate::rclone::state::ENGINE_STATE;
+use crate::utils::rclone::endpoints::EndpointHelper;
+use crate::utils::rclone::error::RcloneApiError;
+
+/// POST `payload` to the given rclone rc `endpoint` and deserialize the JSON
+/// response into `T`.
+///
+/// Pass [`serde_json::Value::Null`] (or an empty object) as the payload for
+/// endpoints that take no body.
+pub async fn post_rc<T, P>(
+    state: &RcloneState,
+    endpoint: &str,
+    payload: P,
+) -> Result<T, RcloneApiError>
+where
+    T: DeserializeOwned,
+    P: Serialize,
+{
+    post_rc_with(&state.client, endpoint, payload).await
+}
+
+/// Like [`post_rc`] but against a bare [`reqwest::Client`], for background tasks
+/// that own a cloned client rather than a [`State`](tauri::State) guard.
+pub async fn post_rc_with<T, P>(
+    client: &reqwest::Client,
+    endpoint: &str,
+    payload: P,
+) -> Result<T, RcloneApiError>
+where
+    T: DeserializeOwned,
+    P: Serialize,
+{
+    // The engine populates its API address once it has finished starting; an
+    // empty base URL means a command was issued before the engine was ready.
+    let base = ENGINE_STATE.get_api().0;
+    if base.is_empty() {
+        return Err(RcloneApiError::EngineNotReady);
+    }
+
+    let url = EndpointHelper::build_url(&base, endpoint);
+    let payload = serde_json::to_value(&payload).unwrap_or(Value::Null);
+
+    debug!("📡 POST {url} with payload: {payload}");
+
+    let mut request = client.post(&url);
+    if !payload.is_null() {
+        request = request.json(&payload);
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| RcloneApiError::Connection(e.to_string()))?;
+
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+
+    if !status.is_success() {
+        return Err(RcloneApiError::Http {
+            status: status.as_u16(),
+            body,
+        });
+    }
+
+    serde_json::from_str(&body).map_err(|e| RcloneApiError::Parse(e.to_string()))
+}
@@ -0,0 +1,33 @@
+//! Structured error type for rclone remote-control calls.
+//!
+//! Commands used to return `Result<_, String>` built from ad-hoc `format!`
+//! calls, which collapsed transport failures, non-2xx responses and JSON parse
+//! errors into indistinguishable strings. [`RcloneApiError`] preserves that
+//! distinction and is `Serialize`/`Deserialize`, so it crosses the Tauri
+//! boundary with its structure intact and the frontend can branch on the
+//! variant — e.g. a reconnect prompt on [`RcloneApiError::Connection`] versus a
+//! parse-bug report on [`RcloneApiError::Parse`].
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// An error returned by any rclone rc command.
+#[derive(Debug, Clone, Error, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "kind", content = "detail")]
+pub enum RcloneApiError {
+    /// The request never reached the engine (socket/DNS/timeout).
+    #[error("connection error: {0}")]
+    Connection(String),
+
+    /// The engine replied with a non-2xx status.
+    #[error("HTTP {status}: {body}")]
+    Http { status: u16, body: String },
+
+    /// The response body could not be deserialized into the expected type.
+    #[error("parse error: {0}")]
+    Parse(String),
+
+    /// The rclone engine has not finished starting.
+    #[error("engine not ready")]
+    EngineNotReady,
+}